@@ -0,0 +1,52 @@
+// 问候功能模块：DTO、handler、存储访问集中在一处
+use axum::{extract::State, routing::post, Json, Router};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{AppError, AppState};
+
+#[derive(Deserialize)]
+struct GreetBody {
+    name: String,
+}
+
+#[derive(serde::Serialize, sqlx::FromRow)]
+struct GreetingRecord {
+    id: i64,
+    message: String,
+    created_at: DateTime<Utc>,
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new().route("/", post(api_greet).get(get_greetings))
+}
+
+async fn api_greet(
+    State(state): State<AppState>,
+    Json(params): Json<GreetBody>,
+) -> Result<Json<GreetingRecord>, AppError> {
+    if params.name.trim().is_empty() {
+        return Err(AppError::BadRequest("name must not be empty".to_string()));
+    }
+
+    let greeting = format!("Hello, {}!", params.name);
+
+    let record = sqlx::query_as::<_, GreetingRecord>(
+        "INSERT INTO greetings (message) VALUES ($1) RETURNING id, message, created_at",
+    )
+    .bind(greeting)
+    .fetch_one(&state.db)
+    .await?;
+
+    Ok(Json(record))
+}
+
+async fn get_greetings(State(state): State<AppState>) -> Result<Json<Vec<GreetingRecord>>, AppError> {
+    let greetings = sqlx::query_as::<_, GreetingRecord>(
+        "SELECT id, message, created_at FROM greetings ORDER BY created_at",
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(greetings))
+}
@@ -0,0 +1,47 @@
+// 基础页面路由：不依赖数据库，纯粹演示 Axum 的提取器
+use axum::{
+    extract::{Path, Query},
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::AppState;
+
+#[derive(Serialize, Deserialize)]
+struct Greeting {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct GreetQuery {
+    name: Option<String>,
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(root))
+        .route("/hello", get(hello))
+        .route("/greet/:name", get(greet_name))
+        .route("/greet", get(greet_query))
+}
+
+async fn root() -> impl IntoResponse {
+    "Hello, World!"
+}
+
+async fn hello() -> impl IntoResponse {
+    Json(Greeting {
+        message: "Hello, World!".to_string(),
+    })
+}
+
+async fn greet_name(Path(name): Path<String>) -> impl IntoResponse {
+    format!("Hello, {}!", name)
+}
+
+async fn greet_query(Query(params): Query<GreetQuery>) -> impl IntoResponse {
+    let name = params.name.unwrap_or_else(|| "World".to_string());
+    format!("Hello, {}!", name)
+}
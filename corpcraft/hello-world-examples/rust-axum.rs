@@ -1,130 +1,133 @@
 // Axum Hello World - 现代 Rust Web 框架
-use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
-    response::IntoResponse,
-    routing::{get, post},
-    Json, Router,
-};
-use serde::{Deserialize, Serialize};
+use axum::{http::StatusCode, response::IntoResponse, Json, Router};
+use sqlx::postgres::PgPoolOptions;
+use sqlx::PgPool;
 use std::net::SocketAddr;
-use std::sync::Arc;
+use std::time::Duration;
+use tower_http::compression::CompressionLayer;
+use tower_http::cors::CorsLayer;
+use tower_http::timeout::TimeoutLayer;
+use tower_http::trace::TraceLayer;
 
-// 模型定义
-#[derive(Serialize, Deserialize)]
-struct Greeting {
-    message: String,
-}
-
-#[derive(Deserialize)]
-struct GreetQuery {
-    name: Option<String>,
-}
-
-#[derive(Deserialize)]
-struct GreetBody {
-    name: String,
-}
+mod pages;
+mod greetings;
 
 // 应用状态
 #[derive(Clone)]
-struct AppState {
-    greetings: Vec<String>,
+pub(crate) struct AppState {
+    pub(crate) db: PgPool,
 }
 
 #[tokio::main]
 async fn main() {
-    // 初始化应用状态
-    let app_state = AppState {
-        greetings: vec![
-            "Hello, World!".to_string(),
-            "Hello, Rust!".to_string(),
-        ],
-    };
-
-    // 创建路由
+    // 初始化 tracing，替代手写的 println! 日志
+    tracing_subscriber::fmt::init();
+
+    // 从环境变量读取数据库连接串，构建连接池
+    let database_url =
+        std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+
+    let db = PgPoolOptions::new()
+        .max_connections(5)
+        .connect(&database_url)
+        .await
+        .expect("failed to connect to Postgres");
+
+    sqlx::migrate!("./migrations")
+        .run(&db)
+        .await
+        .expect("failed to run migrations");
+
+    let app_state = AppState { db };
+
+    // 允许的跨域来源，默认只放行本地开发前端
+    let cors_origin = std::env::var("CORS_ALLOWED_ORIGIN")
+        .unwrap_or_else(|_| "http://localhost:5173".to_string());
+    let cors = CorsLayer::new()
+        .allow_origin(cors_origin.parse::<axum::http::HeaderValue>().unwrap())
+        .allow_methods(tower_http::cors::Any)
+        .allow_headers(tower_http::cors::Any);
+
+    // 按功能模块组合路由：基础页面合并在根路径下，
+    // 问候功能嵌套在 /greetings 前缀下
     let app = Router::new()
-        // 基础路由
-        .route("/", get(root))
-        .route("/hello", get(hello))
-
-        // 路径参数
-        .route("/greet/:name", get(greet_name))
-
-        // 查询参数
-        .route("/greet", get(greet_query))
-
-        // JSON 请求体
-        .route("/api/greet", post(api_greet))
-
-        // 获取列表
-        .route("/greetings", get(get_greetings))
-
-        // 带状态的路由
-        .with_state(app_state);
-
-    // 启动服务器
-    let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
+        .merge(pages::router())
+        .nest("/greetings", greetings::router())
+        .with_state(app_state)
+        // tower-http 中间件栈：结构化日志、CORS、压缩、超时
+        .layer(TraceLayer::new_for_http())
+        .layer(cors)
+        .layer(CompressionLayer::new())
+        .layer(TimeoutLayer::new(Duration::from_secs(10)));
+
+    // 启动服务器，host/port 可通过环境变量覆盖
+    let host = std::env::var("HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
+    let port = std::env::var("PORT")
+        .ok()
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(3000);
+    let addr: SocketAddr = format!("{host}:{port}").parse().expect("invalid HOST/PORT");
     println!("listening on http://{}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .unwrap();
 }
 
-// 处理函数
-async fn root() -> impl IntoResponse {
-    "Hello, World!"
-}
+// 监听 Ctrl-C / SIGTERM，用于优雅关闭，保证数据库连接池正常释放
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl-C handler");
+    };
 
-async fn hello() -> impl IntoResponse {
-    Json(Greeting {
-        message: "Hello, World!".to_string(),
-    })
-}
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
 
-async fn greet_name(Path(name): Path<String>) -> impl IntoResponse {
-    format!("Hello, {}!", name)
-}
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
 
-async fn greet_query(Query(params): Query<GreetQuery>) -> impl IntoResponse {
-    let name = params.name.unwrap_or_else(|| "World".to_string());
-    format!("Hello, {}!", name)
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
 }
 
-async fn api_greet(
-    Json(params): Json<GreetBody>,
-    State(state): State<AppState>,
-) -> impl IntoResponse {
-    let greeting = format!("Hello, {}!", params.name);
-
-    // 添加到状态
-    let mut greetings = state.greetings.clone();
-    greetings.push(greeting.clone());
-
-    (StatusCode::OK, Json(Greeting { message: greeting }))
+// 统一错误类型，避免 handler 里到处 unwrap
+#[derive(Debug)]
+pub(crate) enum AppError {
+    NotFound,
+    BadRequest(String),
+    Internal(anyhow::Error),
 }
 
-async fn get_greetings(State(state): State<AppState>) -> impl IntoResponse {
-    Json(state.greetings)
+impl IntoResponse for AppError {
+    fn into_response(self) -> axum::response::Response {
+        let (status, message) = match self {
+            AppError::NotFound => (StatusCode::NOT_FOUND, "not found".to_string()),
+            AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            AppError::Internal(err) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+            }
+        };
+
+        (status, Json(serde_json::json!({ "error": message }))).into_response()
+    }
 }
 
-// 中间件示例
-use axum::middleware;
-use axum::extract::Request;
-
-async fn logging_middleware(req: Request, next: middleware::Next) -> impl IntoResponse {
-    println!("Request: {} {}", req.method(), req.uri());
-
-    let response = next.run(req).await;
-
-    println!("Response: {}", response.status());
-
-    response
+impl From<sqlx::Error> for AppError {
+    fn from(err: sqlx::Error) -> Self {
+        match err {
+            sqlx::Error::RowNotFound => AppError::NotFound,
+            other => AppError::Internal(other.into()),
+        }
+    }
 }
-
-// 添加中间件的示例（注释掉，避免冲突）
-/*
-let app = Router::new()
-    .route("/", get(root))
-    .layer(middleware::from_fn(logging_middleware));
-*/
\ No newline at end of file